@@ -12,9 +12,10 @@ fn main() {
     let mut resolver = Resolver::default();
     let url_fetcher = DefaultUrlFetcher {};
     let pom_parser = DefaultPomParser {};
+    let metadata_parser = DefaultMetadataParser {};
 
     let project = resolver
-        .build_effective_pom(&artifact, &url_fetcher, &pom_parser)
+        .build_effective_pom(&artifact, &url_fetcher, &pom_parser, &metadata_parser)
         .unwrap();
 
     // print out all dependencies with "compile" scope
@@ -1,10 +1,54 @@
+use std::io::Read;
+
 use crate::*;
 pub struct DefaultUrlFetcher {}
 
 impl UrlFetcher for DefaultUrlFetcher {
     fn fetch(&self, url: &str) -> Result<String, ResolverError> {
-        let text = ureq::get(url.into()).call().unwrap().into_string();
-        Ok(text.unwrap())
+        let response = ureq::get(url.into())
+            .call()
+            .map_err(|e| ResolverError::invalid_data(&format!("failed to fetch {}: {}", url, e)))?;
+
+        response.into_string().map_err(|e| {
+            ResolverError::invalid_data(&format!("failed to read response from {}: {}", url, e))
+        })
+    }
+
+    fn fetch_bytes(&self, url: &str) -> Result<Vec<u8>, ResolverError> {
+        let response = ureq::get(url.into())
+            .call()
+            .map_err(|e| ResolverError::invalid_data(&format!("failed to fetch {}: {}", url, e)))?;
+
+        let mut bytes = Vec::new();
+        response
+            .into_reader()
+            .read_to_end(&mut bytes)
+            .map_err(|e| {
+                ResolverError::invalid_data(&format!("failed to read response from {}: {}", url, e))
+            })?;
+
+        Ok(bytes)
+    }
+}
+
+/// Wraps another [`UrlFetcher`] and verifies every fetched artifact against
+/// its repository-published `.sha1`/`.md5` checksum, independent of
+/// [`Resolver::verify_checksums`](crate::Resolver::verify_checksums).
+pub struct VerifyingUrlFetcher<UF> {
+    pub inner: UF,
+}
+
+impl<UF: UrlFetcher> UrlFetcher for VerifyingUrlFetcher<UF> {
+    fn fetch(&self, url: &str) -> Result<String, ResolverError> {
+        let content = self.inner.fetch(url)?;
+        crate::verify_checksum(url, content.as_bytes(), &self.inner)?;
+        Ok(content)
+    }
+
+    fn fetch_bytes(&self, url: &str) -> Result<Vec<u8>, ResolverError> {
+        let content = self.inner.fetch_bytes(url)?;
+        crate::verify_checksum(url, &content, &self.inner)?;
+        Ok(content)
     }
 }
 
@@ -39,14 +83,33 @@ fn parse_parent(n: &roxmltree::Node) -> Option<Parent> {
     })
 }
 
+fn parse_exclusion(n: &roxmltree::Node) -> DependencyKey {
+    DependencyKey {
+        group_id: node_text(n, "groupId"),
+        artifact_id: node_text(n, "artifactId"),
+    }
+}
+
+fn parse_exclusions(n: &roxmltree::Node) -> Vec<DependencyKey> {
+    match node(n, "exclusions") {
+        Some(n) => n
+            .children()
+            .filter(|child| child.is_element() && child.has_tag_name("exclusion"))
+            .map(|child| parse_exclusion(&child))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
 fn parse_dependency(n: &roxmltree::Node) -> Dependency {
     Dependency {
         artifact_fqn: parse_gav(n),
         scope: node_text(n, "scope"),
+        exclusions: parse_exclusions(n),
     }
 }
 
-fn parse_dependencies(n: &roxmltree::Node) -> HashMap<DependencyKey, Dependency> {
+fn parse_dependencies(n: &roxmltree::Node) -> IndexMap<DependencyKey, Dependency> {
     match node(n, "dependencies") {
         Some(n) => n
             .children()
@@ -56,7 +119,7 @@ fn parse_dependencies(n: &roxmltree::Node) -> HashMap<DependencyKey, Dependency>
                 (dep.get_key(), dep)
             })
             .collect(),
-        _ => HashMap::new(),
+        _ => IndexMap::new(),
     }
 }
 
@@ -85,3 +148,32 @@ impl PomParser for DefaultPomParser {
         })
     }
 }
+
+pub struct DefaultMetadataParser {}
+
+impl MetadataParser for DefaultMetadataParser {
+    fn parse_versions(&self, input: String) -> Result<Vec<String>, ResolverError> {
+        let doc = roxmltree::Document::parse(&input)
+            .map_err(|e| ResolverError::invalid_data(&format!("invalid XML content: {}", e)))?;
+
+        let n = doc.root();
+        let metadata_node = node(&n, "metadata").ok_or_else(|| {
+            ResolverError::invalid_data("invalid XML content, no <metadata> tag")
+        })?;
+
+        let versioning = match node(&metadata_node, "versioning") {
+            Some(n) => n,
+            None => return Ok(Vec::new()),
+        };
+        let versions = match node(&versioning, "versions") {
+            Some(n) => n
+                .children()
+                .filter(|child| child.is_element() && child.has_tag_name("version"))
+                .filter_map(|child| child.text().map(|t| t.to_owned()))
+                .collect(),
+            None => Vec::new(),
+        };
+
+        Ok(versions)
+    }
+}
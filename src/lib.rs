@@ -18,20 +18,28 @@
 //! // default implementations, you can plug in your own
 //! let url_fetcher = DefaultUrlFetcher {};
 //! let pom_parser = DefaultPomParser {};
+//! let metadata_parser = DefaultMetadataParser {};
 //!
 //! let project = resolver
-//!     .build_effective_pom(&artifact, &url_fetcher, &pom_parser)
+//!     .build_effective_pom(&artifact, &url_fetcher, &pom_parser, &metadata_parser)
 //!     .unwrap();
 //! ```
 //!
-//! The `build_effective_pom` call requires a [`UrlFetcher`] and a [`PomParser`].
-//! The [`default_impl`] module provides minimal implementations of of those
-//! traits.
+//! The `build_effective_pom` call requires a [`UrlFetcher`], a [`PomParser`]
+//! and a [`MetadataParser`]. The [`default_impl`] module provides minimal
+//! implementations of those traits.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+use indexmap::IndexMap;
 
 #[cfg(feature = "default-impl")]
 pub mod default_impl;
+pub mod lockfile;
+pub mod version;
+
+use version::{MavenVersion, VersionRequirement};
 
 #[derive(Default, Debug, Clone, Eq, PartialEq, Hash)]
 pub struct ArtifactFqn {
@@ -136,6 +144,7 @@ impl std::fmt::Display for ArtifactFqn {
 pub struct Dependency {
     pub artifact_fqn: ArtifactFqn,
     pub scope: Option<String>,
+    pub exclusions: Vec<DependencyKey>,
 }
 
 impl Dependency {
@@ -150,6 +159,7 @@ impl Dependency {
         Dependency {
             artifact_fqn: self.artifact_fqn.normalize(parent_id, default_packaging),
             scope: self.scope.or_else(|| Some("compile".to_owned())),
+            exclusions: self.exclusions,
         }
     }
 }
@@ -177,9 +187,14 @@ impl std::fmt::Display for DependencyKey {
     }
 }
 
+// an IndexMap, not a HashMap (as is `Project::dependencies`): the
+// earliest-listed BOM import wins (see `build_effective_pom`), and
+// mediation's declaration-order tie-break (see `resolve_transitive`) needs
+// the POM's original <dependency> order to be honest, which a HashMap's
+// iteration order can't guarantee.
 #[derive(Debug, Clone)]
 pub struct DependencyManagement {
-    pub dependencies: HashMap<DependencyKey, Dependency>,
+    pub dependencies: IndexMap<DependencyKey, Dependency>,
 }
 
 #[derive(Debug, Clone)]
@@ -187,7 +202,7 @@ pub struct Project {
     pub parent: Option<Parent>,
     pub artifact_fqn: ArtifactFqn,
     pub dependency_management: Option<DependencyManagement>,
-    pub dependencies: HashMap<DependencyKey, Dependency>,
+    pub dependencies: IndexMap<DependencyKey, Dependency>,
     pub properties: HashMap<String, String>,
 }
 
@@ -232,15 +247,44 @@ impl ResolverError {
 
 pub trait UrlFetcher {
     fn fetch(&self, url: &str) -> Result<String, ResolverError>;
+
+    /// Same as [`UrlFetcher::fetch`], but for content that isn't guaranteed
+    /// to be valid UTF-8 (e.g. `.jar` artifacts, as opposed to POMs and
+    /// `maven-metadata.xml`, which always are). The default implementation
+    /// just re-encodes [`UrlFetcher::fetch`]'s result; implementations that
+    /// read from the network should override this to read raw bytes instead
+    /// of going through a string first.
+    fn fetch_bytes(&self, url: &str) -> Result<Vec<u8>, ResolverError> {
+        self.fetch(url).map(|s| s.into_bytes())
+    }
 }
 
 pub trait PomParser {
     fn parse(&self, input: String) -> Result<Project, ResolverError>;
 }
 
+pub trait MetadataParser {
+    /// Parses a `maven-metadata.xml` document and returns the list of
+    /// published versions (`<versioning><versions><version>`).
+    fn parse_versions(&self, input: String) -> Result<Vec<String>, ResolverError>;
+}
+
 pub struct Resolver {
     pub repository: Repository,
     pub project_cache: HashMap<ArtifactFqn, Project>,
+    /// When `true`, every artifact fetched through [`UrlFetcher`] is
+    /// checked against its repository-published `.sha1`/`.md5` checksum.
+    /// Off by default to match the previous, unverified behavior.
+    pub verify_checksums: bool,
+    /// An on-disk cache mirroring Maven's local repository layout
+    /// (`<root>/<group-with-slashes>/<artifact>/<version>/<artifact>-<version>.pom`),
+    /// checked before falling back to the network and populated on every
+    /// remote fetch. Defaults to `~/.m2/repository`; `None` disables it and
+    /// falls back to the in-memory `project_cache` only.
+    pub local_repository: Option<PathBuf>,
+    /// When `true`, a POM missing from `local_repository` is a
+    /// `ResolverError` instead of falling through to the network.
+    pub offline: bool,
 }
 
 impl Default for Resolver {
@@ -250,15 +294,18 @@ impl Default for Resolver {
                 base_url: "https://repo.maven.apache.org/maven2".into(),
             },
             project_cache: HashMap::new(),
+            verify_checksums: false,
+            local_repository: dirs::home_dir().map(|home| home.join(".m2").join("repository")),
+            offline: false,
         }
     }
 }
 
 fn normalize_gavs(
-    dependencies: HashMap<DependencyKey, Dependency>,
+    dependencies: IndexMap<DependencyKey, Dependency>,
     parent_fqn: &ArtifactFqn,
     default_packaging: &str,
-) -> HashMap<DependencyKey, Dependency> {
+) -> IndexMap<DependencyKey, Dependency> {
     dependencies
         .into_iter()
         .map(|(_, dep)| {
@@ -268,6 +315,81 @@ fn normalize_gavs(
         .collect()
 }
 
+// derives the effective scope of a transitively-pulled dependency from the
+// scope of the path that led to it, e.g. a `compile` dep pulled in under a
+// `test` dep becomes `test`. Returns `None` if the dependency isn't
+// propagated further at all (`provided`/`test` scopes are local to the
+// project that declares them and don't leak into their dependents' graphs).
+fn derive_scope<'a>(parent_scope: &str, declared_scope: &'a str) -> Option<&'a str> {
+    if matches!(declared_scope, "provided" | "test") {
+        return None;
+    }
+
+    match parent_scope {
+        "test" => Some("test"),
+        "runtime" => Some("runtime"),
+        "provided" => Some("provided"),
+        _ => Some(if declared_scope == "runtime" {
+            "runtime"
+        } else {
+            "compile"
+        }),
+    }
+}
+
+struct TransitiveQueueItem {
+    dep: Dependency,
+    depth: usize,
+    exclusions: Vec<DependencyKey>,
+}
+
+pub(crate) fn sha1_hex(content: &[u8]) -> String {
+    use sha1::{Digest, Sha1};
+    let mut hasher = Sha1::new();
+    hasher.update(content);
+    format!("{:x}", hasher.finalize())
+}
+
+fn md5_hex(content: &[u8]) -> String {
+    format!("{:x}", md5::compute(content))
+}
+
+/// Fetches and verifies the repository's published checksum for `content`
+/// (already downloaded from `url`): tries `<url>.sha1` first, falling back
+/// to `<url>.md5` if no SHA-1 is published.
+pub(crate) fn verify_checksum<UF: UrlFetcher>(
+    url: &str,
+    content: &[u8],
+    url_fetcher: &UF,
+) -> Result<(), ResolverError> {
+    let (algorithm, published) = match url_fetcher.fetch(&format!("{}.sha1", url)) {
+        Ok(text) => ("sha1", text),
+        Err(_) => ("md5", url_fetcher.fetch(&format!("{}.md5", url))?),
+    };
+
+    // checksum files sometimes carry a trailing " filename"; only the first
+    // whitespace-separated token is the digest itself
+    let expected = published
+        .split_whitespace()
+        .next()
+        .unwrap_or("")
+        .to_lowercase();
+
+    let actual = match algorithm {
+        "sha1" => sha1_hex(content),
+        _ => md5_hex(content),
+    };
+
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(ResolverError::invalid_data(&format!(
+            "checksum mismatch for {}: expected {} ({}), got {}",
+            url, expected, algorithm, actual
+        )))
+    }
+}
+
 impl Resolver {
     pub fn create_url(&self, id: &ArtifactFqn) -> Result<String, ResolverError> {
         // a little helper
@@ -307,21 +429,215 @@ impl Resolver {
         Ok(url)
     }
 
-    pub fn build_effective_pom<UF, P>(
+    /// Builds the URL of an artifact's `maven-metadata.xml`, used to list
+    /// the versions published for a `groupId:artifactId` so a version range
+    /// can be resolved to a concrete version.
+    pub fn create_metadata_url(&self, id: &ArtifactFqn) -> Result<String, ResolverError> {
+        let group_id = id
+            .group_id
+            .as_ref()
+            .ok_or_else(|| ResolverError::missing_parameter(id, &"groupId"))?;
+        let artifact_id = id
+            .artifact_id
+            .as_ref()
+            .ok_or_else(|| ResolverError::missing_parameter(id, &"artifactId"))?;
+
+        Ok(format!(
+            "{}/{}/{}/maven-metadata.xml",
+            self.repository.base_url,
+            group_id.replace(".", "/"),
+            artifact_id
+        ))
+    }
+
+    /// The on-disk path a POM would live at in `local_repository`, mirroring
+    /// Maven's own local repository layout. Returns `None` if disk caching
+    /// is disabled or `id` isn't fully qualified yet.
+    fn local_pom_path(&self, id: &ArtifactFqn) -> Option<PathBuf> {
+        let root = self.local_repository.as_ref()?;
+        let group_id = id.group_id.as_ref()?;
+        let artifact_id = id.artifact_id.as_ref()?;
+        let version = id.version.as_ref()?;
+
+        Some(
+            root.join(group_id.replace('.', "/"))
+                .join(artifact_id)
+                .join(version)
+                .join(format!("{}-{}.pom", artifact_id, version)),
+        )
+    }
+
+    /// Fetches a POM's raw text, checking the on-disk `local_repository`
+    /// cache before falling back to `url_fetcher`. A successful remote
+    /// fetch is written back to disk for subsequent runs (and other tools,
+    /// including a real `mvn`) to reuse. In `offline` mode, a POM missing
+    /// from disk is a hard error instead of a network fetch.
+    fn fetch_pom_text<UF: UrlFetcher>(
+        &self,
+        project_id: &ArtifactFqn,
+        url: &str,
+        url_fetcher: &UF,
+    ) -> Result<String, ResolverError> {
+        let local_path = self.local_pom_path(project_id);
+
+        if let Some(path) = &local_path {
+            if path.exists() {
+                log::debug!("reading {} from {}", project_id, path.display());
+                return std::fs::read_to_string(path).map_err(|e| {
+                    ResolverError::invalid_data(&format!(
+                        "failed to read {}: {}",
+                        path.display(),
+                        e
+                    ))
+                });
+            }
+        }
+
+        if self.offline {
+            return Err(ResolverError::cant_resolve(
+                project_id,
+                "offline mode: not found in the local repository",
+            ));
+        }
+
+        log::debug!("fetching {}...", url);
+        let text = url_fetcher.fetch(url)?;
+
+        if self.verify_checksums {
+            verify_checksum(url, text.as_bytes(), url_fetcher)?;
+        }
+
+        if let Some(path) = &local_path {
+            if let Some(parent) = path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            if let Err(e) = std::fs::write(path, &text) {
+                log::debug!("failed to cache {} to {}: {}", project_id, path.display(), e);
+            }
+        }
+
+        Ok(text)
+    }
+
+    /// The on-disk path a `maven-metadata.xml` would live at in
+    /// `local_repository`. Returns `None` if disk caching is disabled or
+    /// `id` isn't at least group/artifact-qualified.
+    fn local_metadata_path(&self, id: &ArtifactFqn) -> Option<PathBuf> {
+        let root = self.local_repository.as_ref()?;
+        let group_id = id.group_id.as_ref()?;
+        let artifact_id = id.artifact_id.as_ref()?;
+
+        Some(
+            root.join(group_id.replace('.', "/"))
+                .join(artifact_id)
+                .join("maven-metadata.xml"),
+        )
+    }
+
+    /// Fetches a `maven-metadata.xml` document's raw text, checking the
+    /// on-disk `local_repository` cache before falling back to
+    /// `url_fetcher`, and honoring `offline` exactly like
+    /// [`Resolver::fetch_pom_text`] does for POMs.
+    fn fetch_metadata_text<UF: UrlFetcher>(
+        &self,
+        id: &ArtifactFqn,
+        url_fetcher: &UF,
+    ) -> Result<String, ResolverError> {
+        let local_path = self.local_metadata_path(id);
+
+        if let Some(path) = &local_path {
+            if path.exists() {
+                log::debug!("reading {} metadata from {}", id, path.display());
+                return std::fs::read_to_string(path).map_err(|e| {
+                    ResolverError::invalid_data(&format!(
+                        "failed to read {}: {}",
+                        path.display(),
+                        e
+                    ))
+                });
+            }
+        }
+
+        if self.offline {
+            return Err(ResolverError::cant_resolve(
+                id,
+                "offline mode: maven-metadata.xml not found in the local repository",
+            ));
+        }
+
+        let url = self.create_metadata_url(id)?;
+        log::debug!("fetching {}...", url);
+        let text = url_fetcher.fetch(&url)?;
+
+        if self.verify_checksums {
+            verify_checksum(&url, text.as_bytes(), url_fetcher)?;
+        }
+
+        if let Some(path) = &local_path {
+            if let Some(parent) = path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            if let Err(e) = std::fs::write(path, &text) {
+                log::debug!("failed to cache {} metadata to {}: {}", id, path.display(), e);
+            }
+        }
+
+        Ok(text)
+    }
+
+    /// Resolves a version range (or soft version) against the repository's
+    /// published versions and rewrites `id` to the highest matching
+    /// concrete version.
+    fn resolve_version_range<UF, MP>(
+        &self,
+        id: &ArtifactFqn,
+        requirement: &VersionRequirement,
+        url_fetcher: &UF,
+        metadata_parser: &MP,
+    ) -> Result<ArtifactFqn, ResolverError>
+    where
+        UF: UrlFetcher,
+        MP: MetadataParser,
+    {
+        let text = self.fetch_metadata_text(id, url_fetcher)?;
+        let versions = metadata_parser.parse_versions(text)?;
+
+        let resolved = versions
+            .iter()
+            .map(|v| MavenVersion::parse(v))
+            .filter(|v| requirement.matches(v))
+            .max()
+            .ok_or_else(|| {
+                ResolverError::cant_resolve(
+                    id,
+                    "no version in maven-metadata.xml satisfies the requested range",
+                )
+            })?;
+
+        Ok(ArtifactFqn {
+            version: Some(resolved.as_str().to_owned()),
+            ..id.clone()
+        })
+    }
+
+    pub fn build_effective_pom<UF, P, MP>(
         &mut self,
         project_id: &ArtifactFqn,
         url_fetcher: &UF,
         pom_parser: &P,
+        metadata_parser: &MP,
     ) -> Result<Project, ResolverError>
     where
         UF: UrlFetcher,
         P: PomParser,
+        MP: MetadataParser,
     {
         log::debug!("building an effective pom for {}", project_id);
 
         let project_id = &project_id.with_packaging("pom");
 
-        let mut project = self.fetch_project(project_id, url_fetcher, pom_parser)?;
+        let mut project =
+            self.fetch_project(project_id, url_fetcher, pom_parser, metadata_parser)?;
 
         if let Some(version) = &project_id.version {
             project
@@ -331,8 +647,12 @@ impl Resolver {
 
         // merge in the dependencies from the parent POM
         if let Some(parent) = &project.parent {
-            let parent_project =
-                self.build_effective_pom(&parent.artifact_fqn, url_fetcher, pom_parser)?;
+            let parent_project = self.build_effective_pom(
+                &parent.artifact_fqn,
+                url_fetcher,
+                pom_parser,
+                metadata_parser,
+            )?;
 
             log::trace!("got a parent POM: {}", parent_project.artifact_fqn);
 
@@ -340,7 +660,7 @@ impl Resolver {
                 .dependencies
                 .into_iter()
                 .filter(|(dep_key, _)| !project.dependencies.contains_key(dep_key))
-                .collect::<HashMap<_, _>>();
+                .collect::<IndexMap<_, _>>();
 
             project.dependencies.extend(extra_deps);
         }
@@ -357,37 +677,229 @@ impl Resolver {
                 .map(|(_, dep)| dep.clone())
                 .collect();
 
+            // the project's own management entries always win; BOM imports only
+            // fill in the gaps, earliest-listed BOM wins among themselves
+            let own_dm = project_dm.dependencies.clone();
+            let mut merged_dm: IndexMap<DependencyKey, Dependency> = IndexMap::new();
+
             for bom in boms {
                 log::trace!("got a BOM artifact: {}", bom.artifact_fqn);
 
                 // TODO add protection against infinite recursion
-                let bom_project =
-                    self.build_effective_pom(&bom.artifact_fqn, url_fetcher, pom_parser)?;
+                let bom_project = self.build_effective_pom(
+                    &bom.artifact_fqn,
+                    url_fetcher,
+                    pom_parser,
+                    metadata_parser,
+                )?;
 
                 if let Some(DependencyManagement {
                     dependencies: bom_deps,
                 }) = bom_project.dependency_management
                 {
-                    project_dm.dependencies.extend(bom_deps);
+                    for (key, dep) in bom_deps {
+                        merged_dm.entry(key).or_insert(dep);
+                    }
                 }
             }
+
+            merged_dm.extend(own_dm);
+            project_dm.dependencies = merged_dm;
+
+            // a dependency without a <version> inherits it (and scope/exclusions,
+            // if not already set) from the fully-merged dependencyManagement;
+            // a bare/soft declared version is also overridable by it, while a
+            // hard range is left alone to be resolved on its own terms later
+            for dep in project.dependencies.values_mut() {
+                let managed = project_dm.dependencies.get(&dep.get_key());
+
+                if dep.artifact_fqn.version.is_none() {
+                    let managed = managed.ok_or_else(|| {
+                        ResolverError::cant_resolve(
+                            &dep.artifact_fqn,
+                            "no version and no matching dependencyManagement entry",
+                        )
+                    })?;
+
+                    dep.artifact_fqn.version = managed.artifact_fqn.version.clone();
+                    if dep.scope.is_none() {
+                        dep.scope = managed.scope.clone();
+                    }
+                    if dep.exclusions.is_empty() {
+                        dep.exclusions = managed.exclusions.clone();
+                    }
+
+                    // the lookup above can succeed against a management entry
+                    // that itself has no <version> (e.g. a malformed BOM) -
+                    // still a dependency we can't resolve
+                    if dep.artifact_fqn.version.is_none() {
+                        return Err(ResolverError::cant_resolve(
+                            &dep.artifact_fqn,
+                            "matching dependencyManagement entry has no version either",
+                        ));
+                    }
+                } else if let Some(managed) = managed {
+                    let declared = dep.artifact_fqn.version.as_deref().unwrap_or_default();
+                    let is_soft = !VersionRequirement::parse(declared)?.is_range();
+
+                    if is_soft {
+                        if let Some(managed_version) = &managed.artifact_fqn.version {
+                            dep.artifact_fqn.version = Some(managed_version.clone());
+                        }
+                    }
+                }
+            }
+
+            project.dependency_management = Some(project_dm);
         };
 
         Ok(project)
     }
 
-    pub fn fetch_project<UF, P>(
+    /// Computes the full transitive dependency closure of `root`, applying
+    /// Maven's "nearest wins" mediation: when the same `groupId:artifactId`
+    /// is reached at more than one depth, the shallowest one wins, ties
+    /// broken by earliest declaration order. Only dependencies whose
+    /// resulting scope is in `scope_filter` are returned (pass an empty
+    /// slice to keep everything).
+    pub fn resolve_transitive<UF, P, MP>(
+        &mut self,
+        root: &ArtifactFqn,
+        scope_filter: &[&str],
+        url_fetcher: &UF,
+        pom_parser: &P,
+        metadata_parser: &MP,
+    ) -> Result<Vec<Dependency>, ResolverError>
+    where
+        UF: UrlFetcher,
+        P: PomParser,
+        MP: MetadataParser,
+    {
+        let root_project =
+            self.build_effective_pom(root, url_fetcher, pom_parser, metadata_parser)?;
+
+        let mut queue: VecDeque<TransitiveQueueItem> = root_project
+            .dependencies
+            .values()
+            .map(|dep| TransitiveQueueItem {
+                dep: dep.clone(),
+                depth: 1,
+                exclusions: dep.exclusions.clone(),
+            })
+            .collect();
+
+        // guards against diamonds/cycles in the POM graph itself
+        let mut visited: HashSet<ArtifactFqn> = HashSet::new();
+        visited.insert(root_project.artifact_fqn.clone());
+
+        // (depth, declaration order, dependency) of the winning entry per key
+        let mut winners: HashMap<DependencyKey, (usize, usize, Dependency)> = HashMap::new();
+        let mut declaration_index = 0usize;
+
+        while let Some(item) = queue.pop_front() {
+            let key = item.dep.get_key();
+            let index = declaration_index;
+            declaration_index += 1;
+
+            // BFS processes shallower depths first, and the queue is FIFO
+            // within a depth, so the first time we see a key it's already
+            // the nearest-wins / first-declared winner - don't re-expand it.
+            if winners.contains_key(&key) {
+                continue;
+            }
+            winners.insert(key, (item.depth, index, item.dep.clone()));
+
+            // note: we still expand a provided/test-scoped item's own
+            // children here - it's the *child's declared* scope that decides
+            // whether it propagates further (handled inside derive_scope
+            // below), not the current item's scope. A compile dep pulled in
+            // under a test dep must still become a (test-scoped) part of the
+            // graph.
+            let scope = item.dep.scope.as_deref().unwrap_or("compile");
+
+            if !visited.insert(item.dep.artifact_fqn.clone()) {
+                continue;
+            }
+
+            let child_project = self.build_effective_pom(
+                &item.dep.artifact_fqn,
+                url_fetcher,
+                pom_parser,
+                metadata_parser,
+            )?;
+
+            // pin the winner to the concrete version its POM was resolved to,
+            // so a declared range doesn't end up in the result
+            if let Some(version) = &child_project.artifact_fqn.version {
+                if let Some((_, _, winner)) = winners.get_mut(&item.dep.get_key()) {
+                    winner.artifact_fqn.version = Some(version.clone());
+                }
+            }
+
+            for child_dep in child_project.dependencies.values() {
+                if item.exclusions.contains(&child_dep.get_key()) {
+                    continue;
+                }
+
+                let declared_scope = child_dep.scope.as_deref().unwrap_or("compile");
+                if let Some(derived_scope) = derive_scope(scope, declared_scope) {
+                    let mut exclusions = item.exclusions.clone();
+                    exclusions.extend(child_dep.exclusions.clone());
+
+                    let mut dep = child_dep.clone();
+                    dep.scope = Some(derived_scope.to_owned());
+
+                    queue.push_back(TransitiveQueueItem {
+                        dep,
+                        depth: item.depth + 1,
+                        exclusions,
+                    });
+                }
+            }
+        }
+
+        let mut resolved: Vec<(usize, usize, Dependency)> = winners.into_values().collect();
+        resolved.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+
+        Ok(resolved
+            .into_iter()
+            .map(|(_, _, dep)| dep)
+            .filter(|dep| {
+                scope_filter.is_empty()
+                    || scope_filter.contains(&dep.scope.as_deref().unwrap_or("compile"))
+            })
+            .collect())
+    }
+
+    pub fn fetch_project<UF, P, MP>(
         &mut self,
         project_id: &ArtifactFqn,
         url_fetcher: &UF,
         pom_parser: &P,
+        metadata_parser: &MP,
     ) -> Result<Project, ResolverError>
     where
         UF: UrlFetcher,
         P: PomParser,
+        MP: MetadataParser,
     {
         // we're looking only for POMs here
-        let project_id = project_id.with_packaging("pom");
+        let mut project_id = project_id.with_packaging("pom");
+
+        // a version range must be resolved against the repository's
+        // published versions before we can look anything up by a concrete
+        // GAV; a soft (bare) version is used as-is
+        if let Some(version) = &project_id.version {
+            let requirement = VersionRequirement::parse(version)?;
+            if requirement.is_range() {
+                project_id = self.resolve_version_range(
+                    &project_id,
+                    &requirement,
+                    url_fetcher,
+                    metadata_parser,
+                )?;
+            }
+        }
 
         // check the cache first
         if let Some(cached_project) = self.project_cache.get(&project_id) {
@@ -395,11 +907,9 @@ impl Resolver {
             return Ok(cached_project.clone());
         }
 
-        // grab the remote POM
+        // grab the POM, preferring the local repository cache over the network
         let url = self.create_url(&project_id)?;
-
-        log::debug!("fetching {}...", url);
-        let text = url_fetcher.fetch(&url)?;
+        let text = self.fetch_pom_text(&project_id, &url, url_fetcher)?;
 
         // parse the POM - it will be our "root" project
         // TODO handle multiple "roots"
@@ -439,4 +949,134 @@ impl Resolver {
 
         Ok(project)
     }
+
+    /// Serializes a resolved dependency set (as returned by
+    /// [`Resolver::resolve_transitive`]) to `path`, pinning every
+    /// dependency's exact concrete version, scope and SHA-1 so ranges and
+    /// `${...}` interpolations are frozen to what was actually selected.
+    pub fn write_lockfile<UF: UrlFetcher>(
+        &self,
+        path: &Path,
+        root: &ArtifactFqn,
+        dependencies: &[Dependency],
+        url_fetcher: &UF,
+    ) -> Result<(), ResolverError> {
+        let root_group_id = root
+            .group_id
+            .clone()
+            .ok_or_else(|| ResolverError::missing_parameter(root, &"groupId"))?;
+        let root_artifact_id = root
+            .artifact_id
+            .clone()
+            .ok_or_else(|| ResolverError::missing_parameter(root, &"artifactId"))?;
+        let root_version = root
+            .version
+            .clone()
+            .ok_or_else(|| ResolverError::missing_parameter(root, &"version"))?;
+
+        let mut locked_dependencies = Vec::with_capacity(dependencies.len());
+        for dep in dependencies {
+            let url = self.create_url(&dep.artifact_fqn)?;
+            let content = url_fetcher.fetch_bytes(&url)?;
+
+            if self.verify_checksums {
+                verify_checksum(&url, &content, url_fetcher)?;
+            }
+
+            locked_dependencies.push(lockfile::LockedDependency {
+                group_id: dep
+                    .artifact_fqn
+                    .group_id
+                    .clone()
+                    .ok_or_else(|| ResolverError::missing_parameter(&dep.artifact_fqn, &"groupId"))?,
+                artifact_id: dep.artifact_fqn.artifact_id.clone().ok_or_else(|| {
+                    ResolverError::missing_parameter(&dep.artifact_fqn, &"artifactId")
+                })?,
+                version: dep.artifact_fqn.version.clone().ok_or_else(|| {
+                    ResolverError::missing_parameter(&dep.artifact_fqn, &"version")
+                })?,
+                packaging: dep
+                    .artifact_fqn
+                    .packaging
+                    .clone()
+                    .unwrap_or_else(|| "jar".to_owned()),
+                classifier: dep.artifact_fqn.classifier.clone(),
+                scope: dep.scope.clone().unwrap_or_else(|| "compile".to_owned()),
+                sha1: sha1_hex(&content),
+            });
+        }
+
+        let lock = lockfile::Lockfile {
+            root_group_id,
+            root_artifact_id,
+            root_version,
+            repository_base_url: self.repository.base_url.clone(),
+            dependencies: locked_dependencies,
+        };
+
+        let text = serde_json::to_string_pretty(&lock).map_err(|e| {
+            ResolverError::invalid_data(&format!("failed to serialize lockfile: {}", e))
+        })?;
+
+        std::fs::write(path, text).map_err(|e| {
+            ResolverError::invalid_data(&format!("failed to write {}: {}", path.display(), e))
+        })
+    }
+
+    /// Reads a lockfile written by [`Resolver::write_lockfile`] and
+    /// reproduces its dependency set deterministically: the pinned GAVs are
+    /// trusted as-is, so no version-range/metadata lookups or mediation
+    /// happen, only a checksum verification on each fetch.
+    pub fn resolve_from_lockfile<UF: UrlFetcher>(
+        path: &Path,
+        url_fetcher: &UF,
+    ) -> Result<(Resolver, Vec<Dependency>), ResolverError> {
+        let text = std::fs::read_to_string(path).map_err(|e| {
+            ResolverError::invalid_data(&format!("failed to read {}: {}", path.display(), e))
+        })?;
+
+        let lock: lockfile::Lockfile = serde_json::from_str(&text).map_err(|e| {
+            ResolverError::invalid_data(&format!("failed to parse {}: {}", path.display(), e))
+        })?;
+
+        let resolver = Resolver {
+            repository: Repository {
+                base_url: lock.repository_base_url,
+            },
+            ..Resolver::default()
+        };
+
+        let mut dependencies = Vec::with_capacity(lock.dependencies.len());
+        for locked in &lock.dependencies {
+            let artifact_fqn = ArtifactFqn {
+                group_id: Some(locked.group_id.clone()),
+                artifact_id: Some(locked.artifact_id.clone()),
+                version: Some(locked.version.clone()),
+                packaging: Some(locked.packaging.clone()),
+                classifier: locked.classifier.clone(),
+            };
+
+            let url = resolver.create_url(&artifact_fqn)?;
+            let content = url_fetcher.fetch_bytes(&url)?;
+            let actual_sha1 = sha1_hex(&content);
+
+            if actual_sha1 != locked.sha1 {
+                return Err(ResolverError::cant_resolve(
+                    &artifact_fqn,
+                    &format!(
+                        "checksum drift: lockfile pins {} but the repository now serves {}",
+                        locked.sha1, actual_sha1
+                    ),
+                ));
+            }
+
+            dependencies.push(Dependency {
+                artifact_fqn,
+                scope: Some(locked.scope.clone()),
+                exclusions: Vec::new(),
+            });
+        }
+
+        Ok((resolver, dependencies))
+    }
 }
@@ -0,0 +1,27 @@
+//! The on-disk format for a resolved dependency set, analogous to
+//! `Cargo.lock`: it pins every dependency to the exact concrete version and
+//! checksum that were selected, so a build can be reproduced later without
+//! re-running version-range lookups, `dependencyManagement` merging or
+//! mediation.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LockedDependency {
+    pub group_id: String,
+    pub artifact_id: String,
+    pub version: String,
+    pub packaging: String,
+    pub classifier: Option<String>,
+    pub scope: String,
+    pub sha1: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Lockfile {
+    pub root_group_id: String,
+    pub root_artifact_id: String,
+    pub root_version: String,
+    pub repository_base_url: String,
+    pub dependencies: Vec<LockedDependency>,
+}
@@ -0,0 +1,282 @@
+//! Maven version comparison and version-range parsing.
+//!
+//! Maven versions aren't plain strings for ordering purposes: `1.10` is
+//! newer than `1.9`, and qualifiers like `alpha`/`beta`/`SNAPSHOT` have a
+//! well-known precedence relative to a plain release. This module
+//! implements a practical subset of Maven's own `ComparableVersion` rules,
+//! plus parsing of the `[x,y)`-style range syntax used in `<version>`
+//! elements.
+
+use std::cmp::Ordering;
+
+use crate::ResolverError;
+
+/// A single Maven version, comparable per Maven's ordering rules: the
+/// version string is split on `.` and `-`, numeric segments compare
+/// numerically, and qualifier segments compare by Maven's known precedence
+/// (`alpha < beta < milestone < rc < snapshot < "" (release) < sp`), with
+/// unrecognized qualifiers sorting alphabetically after the known ones.
+#[derive(Debug, Clone)]
+pub struct MavenVersion {
+    raw: String,
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Segment {
+    Numeric(u64),
+    Qualifier(String),
+}
+
+impl Segment {
+    fn rank(qualifier: &str) -> i32 {
+        match qualifier {
+            "alpha" => 0,
+            "beta" => 1,
+            "milestone" => 2,
+            "rc" | "cr" => 3,
+            "snapshot" => 4,
+            "" => 5,
+            "sp" => 6,
+            _ => 7,
+        }
+    }
+}
+
+impl Ord for Segment {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Segment::Numeric(a), Segment::Numeric(b)) => a.cmp(b),
+            (Segment::Qualifier(a), Segment::Qualifier(b)) => {
+                Segment::rank(a).cmp(&Segment::rank(b)).then_with(|| a.cmp(b))
+            }
+            // an empty/"release" qualifier is equivalent to the segment
+            // being absent entirely, so it only ties with a numeric part
+            // that is itself a no-op trailing zero (Maven's
+            // ComparableVersion strips trailing ".0" segments); any other
+            // numeric value is a real difference
+            (Segment::Numeric(n), Segment::Qualifier(q)) if q.is_empty() => {
+                if *n == 0 {
+                    Ordering::Equal
+                } else {
+                    Ordering::Greater
+                }
+            }
+            (Segment::Qualifier(q), Segment::Numeric(n)) if q.is_empty() => {
+                if *n == 0 {
+                    Ordering::Equal
+                } else {
+                    Ordering::Less
+                }
+            }
+            (Segment::Numeric(_), Segment::Qualifier(_)) => Ordering::Greater,
+            (Segment::Qualifier(_), Segment::Numeric(_)) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for Segment {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl MavenVersion {
+    pub fn parse(raw: &str) -> Self {
+        let segments = raw
+            .split(['.', '-'])
+            .map(|s| {
+                if !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()) {
+                    Segment::Numeric(s.parse().unwrap_or(0))
+                } else {
+                    Segment::Qualifier(s.to_lowercase())
+                }
+            })
+            .collect();
+
+        MavenVersion {
+            raw: raw.to_owned(),
+            segments,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+}
+
+impl Ord for MavenVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let len = self.segments.len().max(other.segments.len());
+        let missing = Segment::Qualifier(String::new());
+
+        for i in 0..len {
+            let a = self.segments.get(i).unwrap_or(&missing);
+            let b = other.segments.get(i).unwrap_or(&missing);
+            match a.cmp(b) {
+                Ordering::Equal => continue,
+                ord => return ord,
+            }
+        }
+
+        Ordering::Equal
+    }
+}
+
+impl PartialOrd for MavenVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// equality follows the Maven ordering above, not the raw string, so e.g.
+// `1.0.0` and `1.0` are equal
+impl PartialEq for MavenVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for MavenVersion {}
+
+/// A single `[x,y)`-style Maven range, either bound being open (unbounded)
+/// or present with an inclusive/exclusive flag.
+#[derive(Debug, Clone)]
+pub struct VersionRange {
+    pub lower: Option<(MavenVersion, bool)>,
+    pub upper: Option<(MavenVersion, bool)>,
+}
+
+impl VersionRange {
+    pub fn matches(&self, version: &MavenVersion) -> bool {
+        let lower_ok = match &self.lower {
+            Some((bound, true)) => version >= bound,
+            Some((bound, false)) => version > bound,
+            None => true,
+        };
+        let upper_ok = match &self.upper {
+            Some((bound, true)) => version <= bound,
+            Some((bound, false)) => version < bound,
+            None => true,
+        };
+        lower_ok && upper_ok
+    }
+}
+
+/// A dependency's requested version: either a "soft" preference (a bare
+/// version, still overridable by `dependencyManagement`/mediation) or a
+/// hard range that the selected version must satisfy.
+#[derive(Debug, Clone)]
+pub enum VersionRequirement {
+    Soft(String),
+    Range(Vec<VersionRange>),
+}
+
+impl VersionRequirement {
+    pub fn parse(spec: &str) -> Result<Self, ResolverError> {
+        let spec = spec.trim();
+
+        if !(spec.starts_with('[') || spec.starts_with('(')) {
+            return Ok(VersionRequirement::Soft(spec.to_owned()));
+        }
+
+        let ranges = split_union(spec)?
+            .iter()
+            .map(|part| parse_one_range(part))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(VersionRequirement::Range(ranges))
+    }
+
+    pub fn matches(&self, version: &MavenVersion) -> bool {
+        match self {
+            VersionRequirement::Soft(v) => &MavenVersion::parse(v) == version,
+            VersionRequirement::Range(ranges) => ranges.iter().any(|r| r.matches(version)),
+        }
+    }
+
+    pub fn is_range(&self) -> bool {
+        matches!(self, VersionRequirement::Range(_))
+    }
+}
+
+/// splits a union of ranges like `[1.0,2.0),[3.0,4.0]` on the top-level
+/// commas, i.e. not the comma separating a single range's own bounds
+fn split_union(spec: &str) -> Result<Vec<String>, ResolverError> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut current = String::new();
+
+    for c in spec.chars() {
+        match c {
+            '[' | '(' => {
+                depth += 1;
+                current.push(c);
+            }
+            ']' | ')' => {
+                depth -= 1;
+                current.push(c);
+                if depth == 0 {
+                    parts.push(std::mem::take(&mut current));
+                }
+            }
+            ',' if depth == 0 => {
+                // separates ranges within the union, not part of either one
+            }
+            _ => current.push(c),
+        }
+    }
+
+    if depth != 0 || !current.trim().is_empty() {
+        return Err(ResolverError::invalid_data(&format!(
+            "unbalanced version range: {}",
+            spec
+        )));
+    }
+
+    if parts.is_empty() {
+        return Err(ResolverError::invalid_data(&format!(
+            "empty version range: {}",
+            spec
+        )));
+    }
+
+    Ok(parts)
+}
+
+fn parse_one_range(range: &str) -> Result<VersionRange, ResolverError> {
+    let range = range.trim();
+    let lower_inclusive = range.starts_with('[');
+    let upper_inclusive = range.ends_with(']');
+
+    let well_formed = (range.starts_with('[') || range.starts_with('('))
+        && (range.ends_with(']') || range.ends_with(')'));
+    if !well_formed {
+        return Err(ResolverError::invalid_data(&format!(
+            "invalid version range: {}",
+            range
+        )));
+    }
+
+    let inner = &range[1..range.len() - 1];
+    let mut bounds = inner.splitn(2, ',');
+    let lower_raw = bounds.next().unwrap_or("").trim();
+    let upper_raw = bounds.next();
+
+    match upper_raw {
+        // a bracketed range with no comma, e.g. "[1.0]", pins an exact version
+        None => {
+            let v = MavenVersion::parse(lower_raw);
+            Ok(VersionRange {
+                lower: Some((v.clone(), true)),
+                upper: Some((v, true)),
+            })
+        }
+        Some(upper_raw) => {
+            let upper_raw = upper_raw.trim();
+            let lower = (!lower_raw.is_empty()).then(|| (MavenVersion::parse(lower_raw), lower_inclusive));
+            let upper = (!upper_raw.is_empty()).then(|| (MavenVersion::parse(upper_raw), upper_inclusive));
+            Ok(VersionRange { lower, upper })
+        }
+    }
+}
@@ -1,10 +1,475 @@
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
 use maven_toolbox::default_impl::*;
+use maven_toolbox::version::*;
 use maven_toolbox::*;
 
 fn init() {
     let _ = env_logger::builder().is_test(true).try_init();
 }
 
+// --- in-memory fakes for UrlFetcher/PomParser/MetadataParser, so the
+// resolution logic itself can be unit-tested without live network calls.
+
+#[derive(Default)]
+struct FakeUrlFetcher {
+    poms: HashMap<String, String>,
+}
+
+impl UrlFetcher for FakeUrlFetcher {
+    fn fetch(&self, url: &str) -> Result<String, ResolverError> {
+        self.poms.get(url).cloned().ok_or_else(|| {
+            ResolverError::invalid_data(&format!("no fake content registered for {}", url))
+        })
+    }
+}
+
+#[derive(Default)]
+struct FakePomParser {
+    projects: HashMap<String, Project>,
+}
+
+impl PomParser for FakePomParser {
+    fn parse(&self, input: String) -> Result<Project, ResolverError> {
+        self.projects
+            .get(&input)
+            .cloned()
+            .ok_or_else(|| ResolverError::invalid_data("no fake project registered"))
+    }
+}
+
+#[derive(Default)]
+struct FakeMetadataParser {
+    versions: Vec<String>,
+}
+
+impl MetadataParser for FakeMetadataParser {
+    fn parse_versions(&self, _input: String) -> Result<Vec<String>, ResolverError> {
+        Ok(self.versions.clone())
+    }
+}
+
+// only implements fetch_bytes, so the lockfile path must go through it -
+// stands in for a real artifact fetcher, which can't decode a binary .jar
+// as a String at all
+#[derive(Default)]
+struct FakeBinaryUrlFetcher {
+    artifacts: HashMap<String, Vec<u8>>,
+}
+
+impl UrlFetcher for FakeBinaryUrlFetcher {
+    fn fetch(&self, url: &str) -> Result<String, ResolverError> {
+        Err(ResolverError::invalid_data(&format!(
+            "{} is not valid UTF-8, use fetch_bytes",
+            url
+        )))
+    }
+
+    fn fetch_bytes(&self, url: &str) -> Result<Vec<u8>, ResolverError> {
+        self.artifacts.get(url).cloned().ok_or_else(|| {
+            ResolverError::invalid_data(&format!("no fake content registered for {}", url))
+        })
+    }
+}
+
+fn fake_resolver() -> Resolver {
+    Resolver {
+        repository: Repository {
+            base_url: "fake://repo".to_owned(),
+        },
+        project_cache: HashMap::new(),
+        verify_checksums: false,
+        local_repository: None,
+        offline: false,
+    }
+}
+
+fn fqn(group: &str, artifact: &str, version: &str) -> ArtifactFqn {
+    ArtifactFqn {
+        group_id: Some(group.to_owned()),
+        artifact_id: Some(artifact.to_owned()),
+        version: Some(version.to_owned()),
+        packaging: Some("jar".to_owned()),
+        classifier: None,
+    }
+}
+
+fn dependency(group: &str, artifact: &str, version: &str, scope: &str) -> Dependency {
+    Dependency {
+        artifact_fqn: fqn(group, artifact, version),
+        scope: Some(scope.to_owned()),
+        exclusions: Vec::new(),
+    }
+}
+
+fn versionless(group: &str, artifact: &str) -> Dependency {
+    Dependency {
+        artifact_fqn: ArtifactFqn {
+            group_id: Some(group.to_owned()),
+            artifact_id: Some(artifact.to_owned()),
+            version: None,
+            packaging: Some("jar".to_owned()),
+            classifier: None,
+        },
+        scope: None,
+        exclusions: Vec::new(),
+    }
+}
+
+fn dm(entries: Vec<Dependency>) -> DependencyManagement {
+    let mut dependencies = IndexMap::new();
+    for d in entries {
+        dependencies.insert(d.get_key(), d);
+    }
+    DependencyManagement { dependencies }
+}
+
+fn project_with_deps(group: &str, artifact: &str, version: &str, deps: Vec<Dependency>) -> Project {
+    let mut dependencies = IndexMap::new();
+    for d in deps {
+        dependencies.insert(d.get_key(), d);
+    }
+    Project {
+        parent: None,
+        artifact_fqn: fqn(group, artifact, version).with_packaging("pom"),
+        dependency_management: None,
+        dependencies,
+        properties: HashMap::new(),
+    }
+}
+
+fn register(
+    resolver: &Resolver,
+    url_fetcher: &mut FakeUrlFetcher,
+    pom_parser: &mut FakePomParser,
+    id: &ArtifactFqn,
+    project: Project,
+) {
+    let url = resolver.create_url(&id.with_packaging("pom")).unwrap();
+    url_fetcher.poms.insert(url.clone(), url.clone());
+    pom_parser.projects.insert(url, project);
+}
+
+#[test]
+fn test_build_effective_pom_fills_in_versions_from_dependency_management() {
+    let mut resolver = fake_resolver();
+
+    let bom_fqn = fqn("bom", "bom-parent", "1.0");
+    let mut bom_project = project_with_deps("bom", "bom-parent", "1.0", vec![]);
+    bom_project.dependency_management =
+        Some(dm(vec![dependency("shared", "from-bom", "4.5.6", "compile")]));
+
+    let root_fqn = fqn("root", "app", "1.0");
+    let mut root_project = project_with_deps(
+        "root",
+        "app",
+        "1.0",
+        vec![versionless("shared", "from-bom"), versionless("own", "managed")],
+    );
+    root_project.dependency_management = Some(dm(vec![
+        // the project's own entry overrides what the BOM provides
+        dependency("shared", "from-bom", "9.9.9", "compile"),
+        dependency("own", "managed", "1.2.3", "compile"),
+        dependency("bom", "bom-parent", "1.0", "import"),
+    ]));
+
+    let mut url_fetcher = FakeUrlFetcher::default();
+    let mut pom_parser = FakePomParser::default();
+    register(&resolver, &mut url_fetcher, &mut pom_parser, &root_fqn, root_project);
+    register(&resolver, &mut url_fetcher, &mut pom_parser, &bom_fqn, bom_project);
+
+    let metadata_parser = FakeMetadataParser::default();
+
+    let project = resolver
+        .build_effective_pom(&root_fqn, &url_fetcher, &pom_parser, &metadata_parser)
+        .unwrap();
+
+    let deps: HashMap<_, _> = project.dependencies.into_iter().collect();
+
+    let shared_key = DependencyKey {
+        group_id: Some("shared".to_owned()),
+        artifact_id: Some("from-bom".to_owned()),
+    };
+    let own_key = DependencyKey {
+        group_id: Some("own".to_owned()),
+        artifact_id: Some("managed".to_owned()),
+    };
+
+    assert_eq!("9.9.9", deps[&shared_key].artifact_fqn.version.as_deref().unwrap());
+    assert_eq!("1.2.3", deps[&own_key].artifact_fqn.version.as_deref().unwrap());
+}
+
+#[test]
+fn test_build_effective_pom_errors_when_no_dependency_management_entry() {
+    let mut resolver = fake_resolver();
+
+    let root_fqn = fqn("root", "app", "1.0");
+    let mut root_project =
+        project_with_deps("root", "app", "1.0", vec![versionless("missing", "entry")]);
+    root_project.dependency_management = Some(dm(vec![]));
+
+    let mut url_fetcher = FakeUrlFetcher::default();
+    let mut pom_parser = FakePomParser::default();
+    register(&resolver, &mut url_fetcher, &mut pom_parser, &root_fqn, root_project);
+
+    let metadata_parser = FakeMetadataParser::default();
+
+    let err = resolver
+        .build_effective_pom(&root_fqn, &url_fetcher, &pom_parser, &metadata_parser)
+        .unwrap_err();
+
+    assert!(matches!(err.kind, ErrorKind::ClientError));
+}
+
+#[test]
+fn test_build_effective_pom_soft_version_is_overridden_by_dependency_management() {
+    let mut resolver = fake_resolver();
+
+    let root_fqn = fqn("root", "app", "1.0");
+    let mut root_project = project_with_deps(
+        "root",
+        "app",
+        "1.0",
+        vec![
+            // a bare/soft version is a preference only - dependencyManagement wins
+            dependency("soft", "dep", "1.0", "compile"),
+            // a hard range is never overridden by dependencyManagement
+            dependency("ranged", "dep", "[1.0,2.0)", "compile"),
+        ],
+    );
+    root_project.dependency_management = Some(dm(vec![
+        dependency("soft", "dep", "2.0", "compile"),
+        dependency("ranged", "dep", "9.9.9", "compile"),
+    ]));
+
+    let mut url_fetcher = FakeUrlFetcher::default();
+    let mut pom_parser = FakePomParser::default();
+    register(&resolver, &mut url_fetcher, &mut pom_parser, &root_fqn, root_project);
+
+    let metadata_parser = FakeMetadataParser::default();
+
+    let project = resolver
+        .build_effective_pom(&root_fqn, &url_fetcher, &pom_parser, &metadata_parser)
+        .unwrap();
+
+    let deps: HashMap<_, _> = project.dependencies.into_iter().collect();
+
+    let soft_key = DependencyKey {
+        group_id: Some("soft".to_owned()),
+        artifact_id: Some("dep".to_owned()),
+    };
+    let ranged_key = DependencyKey {
+        group_id: Some("ranged".to_owned()),
+        artifact_id: Some("dep".to_owned()),
+    };
+
+    assert_eq!("2.0", deps[&soft_key].artifact_fqn.version.as_deref().unwrap());
+    assert_eq!(
+        "[1.0,2.0)",
+        deps[&ranged_key].artifact_fqn.version.as_deref().unwrap()
+    );
+}
+
+#[test]
+fn test_fetch_project_verifies_checksum_when_enabled() {
+    use sha1::{Digest, Sha1};
+
+    let mut resolver = fake_resolver();
+    resolver.verify_checksums = true;
+
+    let root_fqn = fqn("root", "app", "1.0");
+    let root_project = project_with_deps("root", "app", "1.0", vec![]);
+
+    let mut url_fetcher = FakeUrlFetcher::default();
+    let mut pom_parser = FakePomParser::default();
+    register(&resolver, &mut url_fetcher, &mut pom_parser, &root_fqn, root_project);
+
+    let url = resolver.create_url(&root_fqn.with_packaging("pom")).unwrap();
+    let content = url_fetcher.poms.get(&url).unwrap().clone();
+    let mut hasher = Sha1::new();
+    hasher.update(content.as_bytes());
+    let digest = format!("{:x}", hasher.finalize());
+    url_fetcher.poms.insert(format!("{}.sha1", url), digest);
+
+    let metadata_parser = FakeMetadataParser::default();
+
+    resolver
+        .fetch_project(&root_fqn, &url_fetcher, &pom_parser, &metadata_parser)
+        .unwrap();
+}
+
+#[test]
+fn test_fetch_project_rejects_checksum_mismatch() {
+    let mut resolver = fake_resolver();
+    resolver.verify_checksums = true;
+
+    let root_fqn = fqn("root", "app", "1.0");
+    let root_project = project_with_deps("root", "app", "1.0", vec![]);
+
+    let mut url_fetcher = FakeUrlFetcher::default();
+    let mut pom_parser = FakePomParser::default();
+    register(&resolver, &mut url_fetcher, &mut pom_parser, &root_fqn, root_project);
+
+    let url = resolver.create_url(&root_fqn.with_packaging("pom")).unwrap();
+    url_fetcher.poms.insert(
+        format!("{}.sha1", url),
+        "0000000000000000000000000000000000000000".to_owned(),
+    );
+
+    let metadata_parser = FakeMetadataParser::default();
+
+    let err = resolver
+        .fetch_project(&root_fqn, &url_fetcher, &pom_parser, &metadata_parser)
+        .unwrap_err();
+
+    assert!(matches!(err.kind, ErrorKind::ClientError));
+}
+
+fn temp_local_repository(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("maven-toolbox-test-{}", name));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn test_fetch_project_reads_from_disk_cache_without_touching_the_network() {
+    let root_fqn = fqn("root", "app", "1.0");
+    let root_project = project_with_deps("root", "app", "1.0", vec![]);
+
+    let local_repository = temp_local_repository("disk-cache");
+    let pom_path = local_repository
+        .join("root")
+        .join("app")
+        .join("1.0")
+        .join("app-1.0.pom");
+    std::fs::create_dir_all(pom_path.parent().unwrap()).unwrap();
+    std::fs::write(&pom_path, "cached on disk").unwrap();
+
+    let mut resolver = fake_resolver();
+    resolver.local_repository = Some(local_repository);
+    resolver.offline = true;
+
+    // deliberately empty: the resolver must never consult it, since the POM
+    // is already on disk
+    let url_fetcher = FakeUrlFetcher::default();
+    let mut pom_parser = FakePomParser::default();
+    pom_parser
+        .projects
+        .insert("cached on disk".to_owned(), root_project);
+
+    let metadata_parser = FakeMetadataParser::default();
+
+    let project = resolver
+        .fetch_project(&root_fqn, &url_fetcher, &pom_parser, &metadata_parser)
+        .unwrap();
+
+    assert_eq!("app", project.artifact_fqn.artifact_id.unwrap());
+}
+
+#[test]
+fn test_fetch_project_verifies_checksum_for_version_range_metadata() {
+    use sha1::{Digest, Sha1};
+
+    let mut resolver = fake_resolver();
+    resolver.verify_checksums = true;
+
+    let root_fqn = fqn("root", "app", "[1.0,2.0)");
+
+    let mut url_fetcher = FakeUrlFetcher::default();
+    let metadata_url = resolver
+        .create_metadata_url(&ArtifactFqn {
+            group_id: Some("root".to_owned()),
+            artifact_id: Some("app".to_owned()),
+            ..Default::default()
+        })
+        .unwrap();
+    let metadata_content = "<metadata/>".to_owned();
+    url_fetcher
+        .poms
+        .insert(metadata_url.clone(), metadata_content.clone());
+    let mut hasher = Sha1::new();
+    hasher.update(metadata_content.as_bytes());
+    let digest = format!("{:x}", hasher.finalize());
+    url_fetcher
+        .poms
+        .insert(format!("{}.sha1", metadata_url), digest);
+
+    let resolved_fqn = fqn("root", "app", "1.5");
+    let mut pom_parser = FakePomParser::default();
+    register(
+        &resolver,
+        &mut url_fetcher,
+        &mut pom_parser,
+        &resolved_fqn,
+        project_with_deps("root", "app", "1.5", vec![]),
+    );
+    let url = resolver.create_url(&resolved_fqn.with_packaging("pom")).unwrap();
+    let content = url_fetcher.poms.get(&url).unwrap().clone();
+    let mut hasher = Sha1::new();
+    hasher.update(content.as_bytes());
+    let digest = format!("{:x}", hasher.finalize());
+    url_fetcher.poms.insert(format!("{}.sha1", url), digest);
+
+    let metadata_parser = FakeMetadataParser {
+        versions: vec!["1.5".to_owned()],
+    };
+
+    resolver
+        .fetch_project(&root_fqn, &url_fetcher, &pom_parser, &metadata_parser)
+        .unwrap();
+}
+
+#[test]
+fn test_fetch_project_offline_mode_rejects_uncached_version_range() {
+    let root_fqn = fqn("root", "app", "[1.0,2.0)");
+
+    // the resolved POM itself is already on disk, so only the
+    // maven-metadata.xml lookup could reach for the network
+    let local_repository = temp_local_repository("offline-range");
+    let pom_path = local_repository
+        .join("root")
+        .join("app")
+        .join("1.5")
+        .join("app-1.5.pom");
+    std::fs::create_dir_all(pom_path.parent().unwrap()).unwrap();
+    std::fs::write(&pom_path, "cached on disk").unwrap();
+
+    let mut resolver = fake_resolver();
+    resolver.local_repository = Some(local_repository);
+    resolver.offline = true;
+
+    // if offline mode were (incorrectly) bypassed for range resolution, this
+    // fetcher has everything needed to succeed - so success here would mean
+    // the bug is still present
+    let mut url_fetcher = FakeUrlFetcher::default();
+    let metadata_url = resolver
+        .create_metadata_url(&ArtifactFqn {
+            group_id: Some("root".to_owned()),
+            artifact_id: Some("app".to_owned()),
+            ..Default::default()
+        })
+        .unwrap();
+    url_fetcher
+        .poms
+        .insert(metadata_url, "<metadata/>".to_owned());
+
+    let mut pom_parser = FakePomParser::default();
+    pom_parser.projects.insert(
+        "cached on disk".to_owned(),
+        project_with_deps("root", "app", "1.5", vec![]),
+    );
+    let metadata_parser = FakeMetadataParser {
+        versions: vec!["1.5".to_owned()],
+    };
+
+    let err = resolver
+        .fetch_project(&root_fqn, &url_fetcher, &pom_parser, &metadata_parser)
+        .unwrap_err();
+
+    assert!(matches!(err.kind, ErrorKind::ClientError));
+}
+
 #[test]
 #[cfg(feature = "default-impl")]
 fn test_build_effective_pom() {
@@ -19,9 +484,10 @@ fn test_build_effective_pom() {
     let mut resolver = Resolver::default();
     let url_fetcher = DefaultUrlFetcher {};
     let pom_parser = DefaultPomParser {};
+    let metadata_parser = DefaultMetadataParser {};
 
     let project = resolver
-        .build_effective_pom(&root, &url_fetcher, &pom_parser)
+        .build_effective_pom(&root, &url_fetcher, &pom_parser, &metadata_parser)
         .unwrap();
 
     assert!(project.parent.is_some());
@@ -57,9 +523,10 @@ fn test_fetch_project() {
     let mut resolver = Resolver::default();
     let url_fetcher = DefaultUrlFetcher {};
     let pom_parser = DefaultPomParser {};
+    let metadata_parser = DefaultMetadataParser {};
 
     let project = resolver
-        .fetch_project(&root, &url_fetcher, &pom_parser)
+        .fetch_project(&root, &url_fetcher, &pom_parser, &metadata_parser)
         .unwrap();
 
     assert!(project.parent.is_some());
@@ -70,9 +537,300 @@ fn test_fetch_project() {
             &project.parent.unwrap().artifact_fqn.with_packaging("pom"),
             &url_fetcher,
             &pom_parser,
+            &metadata_parser,
         )
         .unwrap();
 
     assert_eq!("parent", parent.artifact_fqn.artifact_id.unwrap());
     assert_eq!(2, resolver.project_cache.len());
 }
+
+#[test]
+#[cfg(feature = "default-impl")]
+fn test_default_metadata_parser_rejects_malformed_xml_instead_of_panicking() {
+    let metadata_parser = DefaultMetadataParser {};
+
+    let err = metadata_parser
+        .parse_versions("<metadata><versioning>".to_owned())
+        .unwrap_err();
+
+    assert!(matches!(err.kind, ErrorKind::ClientError));
+}
+
+#[test]
+fn test_maven_version_ordering() {
+    assert!(MavenVersion::parse("1.9") < MavenVersion::parse("1.10"));
+    assert!(MavenVersion::parse("1.0-alpha-1") < MavenVersion::parse("1.0-beta-1"));
+    assert!(MavenVersion::parse("1.0-SNAPSHOT") < MavenVersion::parse("1.0"));
+    assert!(MavenVersion::parse("1.0") < MavenVersion::parse("1.0-sp1"));
+
+    // a version with a real trailing numeric segment is newer than one
+    // without it (only a trailing *zero* segment is a no-op)
+    assert!(MavenVersion::parse("1.0.1") > MavenVersion::parse("1.0"));
+    assert!(MavenVersion::parse("1.0.0") == MavenVersion::parse("1.0"));
+    assert!(MavenVersion::parse("1.10.1") > MavenVersion::parse("1.10"));
+    assert_eq!(
+        MavenVersion::parse("1.10.1"),
+        ["1.9", "1.10.1", "1.10"]
+            .iter()
+            .map(|v| MavenVersion::parse(v))
+            .max()
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_version_range_matching() {
+    let requirement = VersionRequirement::parse("[1.2,2.0)").unwrap();
+
+    assert!(!requirement.matches(&MavenVersion::parse("1.1")));
+    assert!(requirement.matches(&MavenVersion::parse("1.2")));
+    assert!(requirement.matches(&MavenVersion::parse("1.9")));
+    assert!(!requirement.matches(&MavenVersion::parse("2.0")));
+
+    let requirement = VersionRequirement::parse("(,1.5]").unwrap();
+    assert!(requirement.matches(&MavenVersion::parse("1.5")));
+    assert!(!requirement.matches(&MavenVersion::parse("1.6")));
+
+    let requirement = VersionRequirement::parse("1.2.3").unwrap();
+    assert!(!requirement.is_range());
+    assert!(requirement.matches(&MavenVersion::parse("1.2.3")));
+    assert!(!requirement.matches(&MavenVersion::parse("1.2.4")));
+}
+
+#[test]
+fn test_resolve_transitive_mediation_exclusions_and_scope_propagation() {
+    let mut resolver = fake_resolver();
+
+    let root_fqn = fqn("root", "app", "1.0");
+    let a_fqn = fqn("a", "a", "1.0");
+    let b_fqn = fqn("b", "b", "1.0");
+    let c_fqn = fqn("c", "c", "1.0");
+    let d_fqn = fqn("d", "d", "1.0");
+
+    let root_project = project_with_deps(
+        "root",
+        "app",
+        "1.0",
+        vec![
+            // excludes "d:d" from its own subtree
+            Dependency {
+                artifact_fqn: a_fqn.clone(),
+                scope: Some("compile".to_owned()),
+                exclusions: vec![DependencyKey {
+                    group_id: Some("d".to_owned()),
+                    artifact_id: Some("d".to_owned()),
+                }],
+            },
+            dependency("b", "b", "1.0", "test"),
+        ],
+    );
+
+    // A is declared first, so its "compile" reaches "c:c" before B's "test"
+    // does - nearest/first-declared should win.
+    let a_project = project_with_deps(
+        "a",
+        "a",
+        "1.0",
+        vec![dependency("c", "c", "1.0", "compile"), dependency("d", "d", "1.0", "compile")],
+    );
+    // "d:d" is only reachable through B (A's copy is excluded), and a
+    // test-scoped item's own children must still be walked - that's the
+    // scope propagation fix under test here.
+    let b_project = project_with_deps(
+        "b",
+        "b",
+        "1.0",
+        vec![dependency("c", "c", "1.0", "compile"), dependency("d", "d", "1.0", "compile")],
+    );
+    let c_project = project_with_deps("c", "c", "1.0", vec![]);
+    let d_project = project_with_deps("d", "d", "1.0", vec![]);
+
+    let mut url_fetcher = FakeUrlFetcher::default();
+    let mut pom_parser = FakePomParser::default();
+    for (id, project) in [
+        (&root_fqn, root_project),
+        (&a_fqn, a_project),
+        (&b_fqn, b_project),
+        (&c_fqn, c_project),
+        (&d_fqn, d_project),
+    ] {
+        register(&resolver, &mut url_fetcher, &mut pom_parser, id, project);
+    }
+
+    let metadata_parser = FakeMetadataParser::default();
+
+    let resolved = resolver
+        .resolve_transitive(&root_fqn, &[], &url_fetcher, &pom_parser, &metadata_parser)
+        .unwrap();
+
+    let by_key: HashMap<String, Dependency> = resolved
+        .into_iter()
+        .map(|d| (d.get_key().to_string(), d))
+        .collect();
+
+    assert_eq!("compile", by_key["a:a"].scope.as_deref().unwrap());
+    assert_eq!("test", by_key["b:b"].scope.as_deref().unwrap());
+    // wins via A (shallower/first-declared), keeping A's derived "compile"
+    assert_eq!("compile", by_key["c:c"].scope.as_deref().unwrap());
+    // only reachable via B, since A's copy was excluded
+    assert_eq!("test", by_key["d:d"].scope.as_deref().unwrap());
+}
+
+#[test]
+fn test_resolve_transitive_provided_scope_propagates_to_children() {
+    let mut resolver = fake_resolver();
+
+    let root_fqn = fqn("root", "app", "1.0");
+    let a_fqn = fqn("a", "a", "1.0");
+    let b_fqn = fqn("b", "b", "1.0");
+
+    let root_project = project_with_deps(
+        "root",
+        "app",
+        "1.0",
+        vec![dependency("a", "a", "1.0", "provided")],
+    );
+    // reached only through A's "provided" edge, so it must derive to
+    // "provided" too, not "compile"
+    let a_project =
+        project_with_deps("a", "a", "1.0", vec![dependency("b", "b", "1.0", "compile")]);
+    let b_project = project_with_deps("b", "b", "1.0", vec![]);
+
+    let mut url_fetcher = FakeUrlFetcher::default();
+    let mut pom_parser = FakePomParser::default();
+    for (id, project) in [
+        (&root_fqn, root_project),
+        (&a_fqn, a_project),
+        (&b_fqn, b_project),
+    ] {
+        register(&resolver, &mut url_fetcher, &mut pom_parser, id, project);
+    }
+
+    let metadata_parser = FakeMetadataParser::default();
+
+    let resolved = resolver
+        .resolve_transitive(&root_fqn, &[], &url_fetcher, &pom_parser, &metadata_parser)
+        .unwrap();
+
+    let by_key: HashMap<String, Dependency> = resolved
+        .into_iter()
+        .map(|d| (d.get_key().to_string(), d))
+        .collect();
+
+    assert_eq!("provided", by_key["a:a"].scope.as_deref().unwrap());
+    assert_eq!("provided", by_key["b:b"].scope.as_deref().unwrap());
+}
+
+#[test]
+fn test_resolve_transitive_terminates_on_a_cycle() {
+    let mut resolver = fake_resolver();
+
+    let root_fqn = fqn("root", "app", "1.0");
+    let a_fqn = fqn("a", "a", "1.0");
+
+    let root_project =
+        project_with_deps("root", "app", "1.0", vec![dependency("a", "a", "1.0", "compile")]);
+    // a back-edge to the root artifact itself
+    let a_project =
+        project_with_deps("a", "a", "1.0", vec![dependency("root", "app", "1.0", "compile")]);
+
+    let mut url_fetcher = FakeUrlFetcher::default();
+    let mut pom_parser = FakePomParser::default();
+    register(&resolver, &mut url_fetcher, &mut pom_parser, &root_fqn, root_project);
+    register(&resolver, &mut url_fetcher, &mut pom_parser, &a_fqn, a_project);
+
+    let metadata_parser = FakeMetadataParser::default();
+
+    // the only assertion that matters is that this returns at all instead of
+    // recursing/looping forever on the root <-> a back-edge
+    let resolved = resolver
+        .resolve_transitive(&root_fqn, &[], &url_fetcher, &pom_parser, &metadata_parser)
+        .unwrap();
+
+    assert!(resolved.iter().any(|d| d.get_key().to_string() == "a:a"));
+}
+
+#[test]
+fn test_lockfile_round_trip() {
+    let resolver = fake_resolver();
+
+    let root_fqn = fqn("root", "app", "1.0");
+    let resolved = vec![
+        dependency("g1", "a1", "1.0", "compile"),
+        dependency("g2", "a2", "2.0", "test"),
+    ];
+
+    let mut url_fetcher = FakeUrlFetcher::default();
+    for dep in &resolved {
+        let url = resolver.create_url(&dep.artifact_fqn).unwrap();
+        url_fetcher.poms.insert(url.clone(), format!("content of {}", url));
+    }
+
+    let lockfile_path = temp_local_repository("lockfile-round-trip").join("app.lock.json");
+    std::fs::create_dir_all(lockfile_path.parent().unwrap()).unwrap();
+
+    resolver
+        .write_lockfile(&lockfile_path, &root_fqn, &resolved, &url_fetcher)
+        .unwrap();
+
+    let (locked_resolver, locked_deps) =
+        Resolver::resolve_from_lockfile(&lockfile_path, &url_fetcher).unwrap();
+
+    assert_eq!(resolver.repository.base_url, locked_resolver.repository.base_url);
+
+    let mut locked_keys: Vec<String> = locked_deps.iter().map(|d| d.get_key().to_string()).collect();
+    locked_keys.sort();
+    assert_eq!(vec!["g1:a1".to_owned(), "g2:a2".to_owned()], locked_keys);
+}
+
+#[test]
+fn test_lockfile_round_trip_with_non_utf8_artifact_content() {
+    let resolver = fake_resolver();
+
+    let root_fqn = fqn("root", "app", "1.0");
+    let resolved = vec![dependency("g1", "a1", "1.0", "compile")];
+
+    let mut url_fetcher = FakeBinaryUrlFetcher::default();
+    let url = resolver.create_url(&resolved[0].artifact_fqn).unwrap();
+    // invalid UTF-8: a lone continuation byte
+    url_fetcher.artifacts.insert(url, vec![b'P', b'K', 0x03, 0x04, 0x80]);
+
+    let lockfile_path =
+        temp_local_repository("lockfile-binary-round-trip").join("app.lock.json");
+    std::fs::create_dir_all(lockfile_path.parent().unwrap()).unwrap();
+
+    resolver
+        .write_lockfile(&lockfile_path, &root_fqn, &resolved, &url_fetcher)
+        .unwrap();
+
+    let (_, locked_deps) =
+        Resolver::resolve_from_lockfile(&lockfile_path, &url_fetcher).unwrap();
+
+    assert_eq!(1, locked_deps.len());
+}
+
+#[test]
+fn test_lockfile_detects_checksum_drift() {
+    let resolver = fake_resolver();
+
+    let root_fqn = fqn("root", "app", "1.0");
+    let resolved = vec![dependency("g1", "a1", "1.0", "compile")];
+
+    let mut url_fetcher = FakeUrlFetcher::default();
+    let url = resolver.create_url(&resolved[0].artifact_fqn).unwrap();
+    url_fetcher.poms.insert(url.clone(), "original content".to_owned());
+
+    let lockfile_path = temp_local_repository("lockfile-drift").join("app.lock.json");
+    std::fs::create_dir_all(lockfile_path.parent().unwrap()).unwrap();
+
+    resolver
+        .write_lockfile(&lockfile_path, &root_fqn, &resolved, &url_fetcher)
+        .unwrap();
+
+    // the repository now serves different bytes for the same artifact
+    url_fetcher.poms.insert(url, "tampered content".to_owned());
+
+    let err = Resolver::resolve_from_lockfile(&lockfile_path, &url_fetcher).unwrap_err();
+    assert!(matches!(err.kind, ErrorKind::ClientError));
+}